@@ -8,9 +8,22 @@
 
 //! Deserialize JSON data to a Rust data structure.
 
-use std::{i32, u64};
+use core::{i32, u64, u8};
+#[cfg(feature = "arbitrary_precision")]
+use core::fmt;
+use core::iter;
+use core::marker::PhantomData;
+#[cfg(feature = "arbitrary_precision")]
+use core::result::Result as StdResult;
+#[cfg(feature = "std")]
 use std::io;
-use std::marker::PhantomData;
+
+// Needed for the `.to_owned()` calls in the bareword null/true/false
+// string-concat fallback below -- `std`'s prelude brings this in
+// automatically, but the `alloc` prelude doesn't.
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
 
 use serde::de::{self, Expected, Unexpected};
 
@@ -18,21 +31,205 @@ use super::error::{Error, ErrorCode, Result};
 
 use read::{self, Reference};
 
-pub use read::{Read, IoRead, SliceRead, StrRead};
+#[cfg(feature = "std")]
+pub use read::IoRead;
+pub use read::{MutSliceRead, Read, SliceRead, StrRead};
 
 //////////////////////////////////////////////////////////////////////////////
 
+/// Gates the non-standard relaxations this crate is willing to parse.
+///
+/// Every grammar relaxation this deserializer knows about (comments,
+/// single-quoted strings, unquoted barewords, newline-terminated values, ...)
+/// is expressed as a method on this trait, so a `Dialect` implementation acts
+/// as a compile-time switch: flip a method to `true` and the corresponding
+/// parser branch becomes reachable, leave it `false` and the parser reports a
+/// syntax error instead. `Deserializer::<R, F>` is generic over `F: Dialect`,
+/// so the same state machine backs both strict RFC 8259 JSON and the fully
+/// relaxed HJSON-like dialect this crate used to hard-code.
+pub trait Dialect {
+    /// Whether `#` starts a line comment.
+    fn hash_is_comment() -> bool {
+        false
+    }
+
+    /// Whether `//` starts a line comment.
+    fn allow_line_comments() -> bool {
+        false
+    }
+
+    /// Whether `/* ... */` block comments are recognized.
+    fn allow_block_comments() -> bool {
+        false
+    }
+
+    /// Whether strings may be delimited with `'` in addition to `"`.
+    fn allow_single_quotes() -> bool {
+        false
+    }
+
+    /// Whether a bareword/unquoted run of characters may stand in for an
+    /// object key.
+    fn allow_unquoted_keys() -> bool {
+        false
+    }
+
+    /// Whether a bareword/unquoted run of characters may stand in for a
+    /// string value.
+    fn allow_unquoted_values() -> bool {
+        false
+    }
+
+    /// Whether a newline may terminate a value in place of a comma.
+    fn newline_terminates_value() -> bool {
+        false
+    }
+
+    /// Whether `(...)` is recognized as a sequence delimiter alongside `[...]`.
+    fn parens_are_seq() -> bool {
+        false
+    }
+
+    /// Whether a leading `#` introduces an atom escape (`#t`, `#f`, `#nil`)
+    /// rather than a line comment. Implies `hash_is_comment()` is `false`.
+    fn hash_escapes() -> bool {
+        false
+    }
+
+    /// Whether a comma may appear right before a sequence's or map's closing
+    /// delimiter.
+    fn allow_trailing_commas() -> bool {
+        false
+    }
+
+    /// The recursion limit seeded into `Deserializer::remaining_depth`.
+    fn max_depth() -> usize {
+        128
+    }
+}
+
+/// Strict RFC 8259 JSON: no comments, no single quotes, no barewords, no
+/// newline-delimited values.
+pub struct StrictJson;
+
+impl Dialect for StrictJson {}
+
+/// The relaxed, HJSON-like dialect this crate historically parsed
+/// unconditionally: comments, single-quoted strings, unquoted barewords, and
+/// newline-terminated values are all accepted.
+pub struct Relaxed;
+
+impl Dialect for Relaxed {
+    fn hash_is_comment() -> bool {
+        true
+    }
+
+    fn allow_line_comments() -> bool {
+        true
+    }
+
+    fn allow_block_comments() -> bool {
+        true
+    }
+
+    fn allow_single_quotes() -> bool {
+        true
+    }
+
+    fn allow_unquoted_keys() -> bool {
+        true
+    }
+
+    fn allow_unquoted_values() -> bool {
+        true
+    }
+
+    fn newline_terminates_value() -> bool {
+        true
+    }
+
+    fn allow_trailing_commas() -> bool {
+        true
+    }
+}
+
+/// A Lisp-style S-expression front end sharing the same reader, number, and
+/// comment machinery as the JSON dialects: `(a b c)` is a sequence, bare
+/// tokens are symbol atoms (surfaced as strings), and `#t`/`#f`/`#nil`/`()`
+/// are the boolean and unit literals.
+pub struct Sexpr;
+
+impl Dialect for Sexpr {
+    fn allow_unquoted_keys() -> bool {
+        true
+    }
+
+    fn allow_unquoted_values() -> bool {
+        true
+    }
+
+    fn newline_terminates_value() -> bool {
+        true
+    }
+
+    fn parens_are_seq() -> bool {
+        true
+    }
+
+    fn hash_escapes() -> bool {
+        true
+    }
+
+    fn allow_trailing_commas() -> bool {
+        true
+    }
+}
+
+/// How string content is decoded when a value is requested as bytes via
+/// [`Deserializer::deserialize_bytes`]/`deserialize_byte_buf`.
+///
+/// The default, `Raw`, hands the string's bytes to the visitor verbatim
+/// (including invalid UTF-8 reached through the unescaping machinery). The
+/// other variants treat the string as text-encoded binary -- the
+/// conventional way to round-trip a `Vec<u8>`/`serde_bytes::ByteBuf` field
+/// through JSON, which has no native bytes type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BytesEncoding {
+    /// Hand the string's bytes to the visitor unchanged.
+    Raw,
+    /// Decode the string as standard (`+`/`/`) base64.
+    Base64,
+    /// Decode the string as URL-safe (`-`/`_`) base64.
+    Base64Url,
+    /// Decode the string as base16/hex, case-insensitively.
+    Base16,
+}
+
+impl Default for BytesEncoding {
+    fn default() -> Self {
+        BytesEncoding::Raw
+    }
+}
+
 /// A structure that deserializes JSON into Rust values.
-pub struct Deserializer<R> {
+///
+/// `F` selects the [`Dialect`] consulted for every relaxation decision; it
+/// defaults to nothing in particular here because the various `from_*`
+/// constructors below pin it explicitly (strict by default, with
+/// `with_dialect` available to opt back into `Relaxed`).
+pub struct Deserializer<R, F> {
     read: R,
     str_buf: Vec<u8>,
-    remaining_depth: u8,
+    remaining_depth: usize,
     capture: bool,
+    config: Options,
+    dialect: PhantomData<F>,
 }
 
-impl<'de, R> Deserializer<R>
+impl<'de, R, F> Deserializer<R, F>
 where
     R: read::Read<'de>,
+    F: Dialect,
 {
     /// Create a JSON deserializer from one of the possible serde_json input
     /// sources.
@@ -46,13 +243,49 @@ where
         Deserializer {
             read: read,
             str_buf: Vec::with_capacity(128),
-            remaining_depth: 128,
+            remaining_depth: F::max_depth(),
             capture: false,
+            config: Options::default(),
+            dialect: PhantomData,
         }
     }
+
+    /// Re-dialect this deserializer, swapping which [`Dialect`] governs the
+    /// relaxations it accepts from here on.
+    pub fn with_dialect<F2: Dialect>(self) -> Deserializer<R, F2> {
+        Deserializer {
+            read: self.read,
+            str_buf: self.str_buf,
+            remaining_depth: F2::max_depth(),
+            capture: self.capture,
+            config: self.config,
+            dialect: PhantomData,
+        }
+    }
+
+    /// Overrides the per-instance relaxation flags in [`Options`] consulted
+    /// alongside `F` (its `max_depth` is ignored here; use
+    /// [`set_max_depth`][Self::set_max_depth] for that). Typically reached
+    /// through [`DeserializerBuilder`] instead.
+    pub fn set_config(&mut self, config: Options) {
+        self.config = config;
+    }
+
+    /// Overrides the recursion limit seeded from `F::max_depth()`, so
+    /// callers parsing untrusted input can lower it for DoS resistance, or
+    /// raise it for deeply-nested but trusted documents. Typically reached
+    /// through [`DeserializerBuilder::max_depth`] instead.
+    ///
+    /// Must be called before any value has been parsed, since it simply
+    /// reseeds the depth budget consulted by the `[`/`{` arms rather than
+    /// adjusting whatever depth has already been spent.
+    pub fn set_max_depth(&mut self, max_depth: usize) {
+        self.remaining_depth = max_depth;
+    }
 }
 
-impl<R> Deserializer<read::IoRead<R>>
+#[cfg(feature = "std")]
+impl<R> Deserializer<read::IoRead<R>, StrictJson>
 where
     R: io::Read,
 {
@@ -60,20 +293,310 @@ where
     pub fn from_reader(reader: R) -> Self {
         Deserializer::new(read::IoRead::new(reader))
     }
+
+    /// Creates a JSON deserializer from an `io::Read`, applying `options` up
+    /// front instead of RFC 8259-strict defaults.
+    pub fn from_reader_with_options(reader: R, options: Options) -> Self {
+        options.apply(Deserializer::from_reader(reader))
+    }
 }
 
-impl<'a> Deserializer<read::SliceRead<'a>> {
+impl<'a> Deserializer<read::SliceRead<'a>, StrictJson> {
     /// Creates a JSON deserializer from a `&[u8]`.
     pub fn from_slice(bytes: &'a [u8]) -> Self {
         Deserializer::new(read::SliceRead::new(bytes))
     }
+
+    /// Creates a JSON deserializer from a `&[u8]`, applying `options` up
+    /// front instead of RFC 8259-strict defaults.
+    pub fn from_slice_with_options(bytes: &'a [u8], options: Options) -> Self {
+        options.apply(Deserializer::from_slice(bytes))
+    }
+}
+
+impl<'a> Deserializer<read::MutSliceRead<'a>, StrictJson> {
+    /// Creates a JSON deserializer from a `&mut [u8]`, unescaping strings in
+    /// place instead of into a heap-allocated scratch buffer.
+    ///
+    /// See [`MutSliceRead`] for the in-place unescaping invariant this
+    /// relies on.
+    pub fn from_mut_slice(bytes: &'a mut [u8]) -> Self {
+        Deserializer::new(read::MutSliceRead::new(bytes))
+    }
 }
 
-impl<'a> Deserializer<read::StrRead<'a>> {
+impl<'a> Deserializer<read::StrRead<'a>, StrictJson> {
     /// Creates a JSON deserializer from a `&str`.
     pub fn from_str(s: &'a str) -> Self {
         Deserializer::new(read::StrRead::new(s))
     }
+
+    /// Creates a JSON deserializer from a `&str`, applying `options` up
+    /// front instead of RFC 8259-strict defaults.
+    pub fn from_str_with_options(s: &'a str, options: Options) -> Self {
+        options.apply(Deserializer::from_str(s))
+    }
+}
+
+/// Runtime relaxation toggles consulted alongside the compile-time
+/// [`Dialect`] on every instance of [`Deserializer`], plus a recursion
+/// limit. A flag here and the matching `Dialect` method are OR'd together,
+/// so `DeserializerBuilder` can turn on individual RFC 8259 relaxations
+/// without requiring a dedicated `Dialect` implementation for every
+/// combination.
+///
+/// Also doubles as a single value bundling every relaxation up front, for
+/// callers who'd rather build one value than chain [`DeserializerBuilder`]
+/// calls -- mirroring the `Options`/`Extensions` pattern from crates like
+/// RON.
+///
+/// ```rust
+/// use serde_json::{Deserializer, Options};
+///
+/// let options = Options {
+///     allow_single_quotes: true,
+///     allow_trailing_commas: true,
+///     max_depth: Some(32),
+///     ..Options::default()
+/// };
+///
+/// let de = Deserializer::from_str_with_options("{'a': 1, }", options);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Options {
+    pub allow_single_quotes: bool,
+    pub allow_unquoted_keys: bool,
+    pub allow_unquoted_values: bool,
+    pub allow_newline_separators: bool,
+    pub allow_trailing_commas: bool,
+    pub allow_line_comments: bool,
+    pub allow_block_comments: bool,
+    pub allow_hash_comments: bool,
+    /// Recursion limit seeded into `remaining_depth`, or `None` to keep
+    /// `StrictJson`'s default of 128.
+    pub max_depth: Option<usize>,
+    /// How string content is decoded by `deserialize_bytes`/`deserialize_byte_buf`.
+    pub bytes_encoding: BytesEncoding,
+}
+
+impl Options {
+    fn apply<'de, R, F>(&self, mut de: Deserializer<R, F>) -> Deserializer<R, F>
+    where
+        R: read::Read<'de>,
+        F: Dialect,
+    {
+        de.set_config(*self);
+        if let Some(max_depth) = self.max_depth {
+            de.set_max_depth(max_depth);
+        }
+        de
+    }
+}
+
+/// Builds a [`Deserializer`] with individually toggled RFC 8259 relaxations,
+/// for callers who want progressively JSON5-like leniency without defining a
+/// dedicated [`Dialect`].
+///
+/// ```rust
+/// use serde_json::{DeserializerBuilder, SliceRead};
+///
+/// let de = DeserializerBuilder::new(SliceRead::new(b"{unquoted: 'single quoted', }"))
+///     .allow_unquoted_keys(true)
+///     .allow_single_quotes(true)
+///     .allow_trailing_commas(true)
+///     .build();
+/// ```
+pub struct DeserializerBuilder<R> {
+    read: R,
+    config: Options,
+    max_depth: Option<usize>,
+}
+
+impl<R> DeserializerBuilder<R> {
+    /// Starts a builder over the given input with every relaxation disabled
+    /// (strict RFC 8259 JSON).
+    pub fn new(read: R) -> Self {
+        DeserializerBuilder {
+            read: read,
+            config: Options::default(),
+            max_depth: None,
+        }
+    }
+
+    /// Overrides the recursion limit, in place of `StrictJson`'s default of
+    /// 128. Lower it to harden against stack-overflow DoS from untrusted
+    /// input, or raise it for tools that need to accept deeply-nested but
+    /// trusted documents.
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Whether strings may be delimited with `'` in addition to `"`.
+    pub fn allow_single_quotes(mut self, allow: bool) -> Self {
+        self.config.allow_single_quotes = allow;
+        self
+    }
+
+    /// Whether a bareword/unquoted run of characters may stand in for an
+    /// object key.
+    pub fn allow_unquoted_keys(mut self, allow: bool) -> Self {
+        self.config.allow_unquoted_keys = allow;
+        self
+    }
+
+    /// Whether a bareword/unquoted run of characters may stand in for a
+    /// string value.
+    pub fn allow_unquoted_values(mut self, allow: bool) -> Self {
+        self.config.allow_unquoted_values = allow;
+        self
+    }
+
+    /// Whether a newline may terminate a value in place of a comma.
+    pub fn allow_newline_separators(mut self, allow: bool) -> Self {
+        self.config.allow_newline_separators = allow;
+        self
+    }
+
+    /// Whether a comma may appear right before a sequence's or map's closing
+    /// delimiter.
+    pub fn allow_trailing_commas(mut self, allow: bool) -> Self {
+        self.config.allow_trailing_commas = allow;
+        self
+    }
+
+    /// Whether `//` starts a line comment.
+    pub fn allow_line_comments(mut self, allow: bool) -> Self {
+        self.config.allow_line_comments = allow;
+        self
+    }
+
+    /// Whether `/* ... */` block comments are recognized.
+    pub fn allow_block_comments(mut self, allow: bool) -> Self {
+        self.config.allow_block_comments = allow;
+        self
+    }
+
+    /// Whether `#` starts a line comment.
+    pub fn allow_hash_comments(mut self, allow: bool) -> Self {
+        self.config.allow_hash_comments = allow;
+        self
+    }
+
+    /// How string content is decoded by `deserialize_bytes`/`deserialize_byte_buf`.
+    /// Defaults to [`BytesEncoding::Raw`].
+    pub fn bytes_encoding(mut self, encoding: BytesEncoding) -> Self {
+        self.config.bytes_encoding = encoding;
+        self
+    }
+}
+
+impl<'de, R> DeserializerBuilder<R>
+where
+    R: read::Read<'de>,
+{
+    /// Builds the configured [`Deserializer`], using [`StrictJson`] as the
+    /// base dialect so the only relaxations in effect are the ones set on
+    /// this builder.
+    pub fn build(self) -> Deserializer<R, StrictJson> {
+        let mut de = Deserializer::new(self.read);
+        de.set_config(self.config);
+        if let Some(max_depth) = self.max_depth {
+            de.set_max_depth(max_depth);
+        }
+        de
+    }
+}
+
+/// Maps one base64 alphabet character to its 6-bit value, using the
+/// URL-safe (`-`/`_`) alphabet instead of the standard (`+`/`/`) one when
+/// `url_safe` is set. Padding (`=`) is handled by the caller.
+fn base64_value(byte: u8, url_safe: bool) -> Option<u8> {
+    match byte {
+        b'A'...b'Z' => Some(byte - b'A'),
+        b'a'...b'z' => Some(byte - b'a' + 26),
+        b'0'...b'9' => Some(byte - b'0' + 52),
+        b'+' if !url_safe => Some(62),
+        b'/' if !url_safe => Some(63),
+        b'-' if url_safe => Some(62),
+        b'_' if url_safe => Some(63),
+        _ => None,
+    }
+}
+
+/// Decodes `input` as base64 (standard or URL-safe, per `url_safe`),
+/// tolerating missing trailing `=` padding. Returns `None` on any
+/// character outside the chosen alphabet or a truncated final group.
+fn decode_base64(input: &[u8], url_safe: bool) -> Option<Vec<u8>> {
+    let padding = input.iter().rev().take_while(|&&b| b == b'=').count();
+    let data = &input[..input.len() - padding];
+    if data.len() % 4 == 1 {
+        return None;
+    }
+
+    let mut out = Vec::with_capacity(data.len() / 4 * 3 + 3);
+    let mut group = [0u8; 4];
+    let mut group_len = 0;
+
+    for &byte in data {
+        group[group_len] = match base64_value(byte, url_safe) {
+            Some(value) => value,
+            None => return None,
+        };
+        group_len += 1;
+
+        if group_len == 4 {
+            out.push((group[0] << 2) | (group[1] >> 4));
+            out.push((group[1] << 4) | (group[2] >> 2));
+            out.push((group[2] << 6) | group[3]);
+            group_len = 0;
+        }
+    }
+
+    match group_len {
+        0 => {}
+        2 => out.push((group[0] << 2) | (group[1] >> 4)),
+        3 => {
+            out.push((group[0] << 2) | (group[1] >> 4));
+            out.push((group[1] << 4) | (group[2] >> 2));
+        }
+        _ => return None,
+    }
+
+    Some(out)
+}
+
+/// Maps one base16/hex digit to its 4-bit value, accepting either case.
+fn hex_value(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'...b'9' => Some(byte - b'0'),
+        b'a'...b'f' => Some(byte - b'a' + 10),
+        b'A'...b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Decodes `input` as base16/hex. Returns `None` if `input` has an odd
+/// length or contains a non-hex-digit byte.
+fn decode_base16(input: &[u8]) -> Option<Vec<u8>> {
+    if input.len() % 2 != 0 {
+        return None;
+    }
+
+    let mut out = Vec::with_capacity(input.len() / 2);
+    for pair in input.chunks(2) {
+        let hi = match hex_value(pair[0]) {
+            Some(value) => value,
+            None => return None,
+        };
+        let lo = match hex_value(pair[1]) {
+            Some(value) => value,
+            None => return None,
+        };
+        out.push((hi << 4) | lo);
+    }
+
+    Some(out)
 }
 
 macro_rules! overflow {
@@ -109,7 +632,182 @@ impl Number {
     }
 }
 
-impl<'de, R: Read<'de>> Deserializer<R> {
+/// Sentinel map key `deserialize_any` uses to hand a [`RawNumber`] back to
+/// its own `Deserialize` impl instead of an eagerly-rounded `f64`. Private to
+/// this crate: nothing else should ever produce or match on a map with this
+/// key.
+#[cfg(feature = "arbitrary_precision")]
+const TOKEN: &'static str = "$serde_json::private::RawNumber";
+
+/// The exact text of a JSON number, preserved without rounding.
+///
+/// Ordinarily `deserialize_any` parses a number into an `f64`/`u64`/`i64` as
+/// it goes, which loses precision for large integers or long decimal
+/// expansions. When the `arbitrary_precision` feature is enabled, numbers
+/// encountered through `deserialize_any` are instead captured verbatim and
+/// handed to the visitor as this type, so callers can re-parse the digits
+/// with whatever correctly-rounded or arbitrary-precision algorithm they
+/// need.
+#[cfg(feature = "arbitrary_precision")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RawNumber(String);
+
+#[cfg(feature = "arbitrary_precision")]
+impl RawNumber {
+    /// The exact digits this number was parsed from, e.g. `"3.1400"` or
+    /// `"-12345678901234567890"`.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(feature = "arbitrary_precision")]
+impl<'de> de::Deserialize<'de> for RawNumber {
+    fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        struct RawNumberVisitor;
+
+        impl<'de> de::Visitor<'de> for RawNumberVisitor {
+            type Value = RawNumber;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a JSON number")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> StdResult<RawNumber, V::Error>
+            where
+                V: de::MapAccess<'de>,
+            {
+                let key: Option<RawNumberField> = try!(map.next_key());
+                if key.is_none() {
+                    return Err(de::Error::invalid_type(Unexpected::Map, &self));
+                }
+                let raw: String = try!(map.next_value());
+                Ok(RawNumber(raw))
+            }
+        }
+
+        deserializer.deserialize_any(RawNumberVisitor)
+    }
+}
+
+#[cfg(feature = "arbitrary_precision")]
+struct RawNumberField;
+
+#[cfg(feature = "arbitrary_precision")]
+impl<'de> de::Deserialize<'de> for RawNumberField {
+    fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        struct FieldVisitor;
+
+        impl<'de> de::Visitor<'de> for FieldVisitor {
+            type Value = ();
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("the raw number sentinel field")
+            }
+
+            fn visit_str<E>(self, s: &str) -> StdResult<(), E>
+            where
+                E: de::Error,
+            {
+                if s == TOKEN {
+                    Ok(())
+                } else {
+                    Err(de::Error::custom("expected raw number sentinel field"))
+                }
+            }
+        }
+
+        try!(deserializer.deserialize_identifier(FieldVisitor));
+        Ok(RawNumberField)
+    }
+}
+
+/// One-entry `MapAccess` feeding a captured raw number token back through
+/// [`RawNumber`]'s sentinel-key protocol.
+#[cfg(feature = "arbitrary_precision")]
+struct RawNumberAccess {
+    raw: Option<String>,
+}
+
+#[cfg(feature = "arbitrary_precision")]
+impl RawNumberAccess {
+    fn new(raw: String) -> Self {
+        RawNumberAccess { raw: Some(raw) }
+    }
+}
+
+#[cfg(feature = "arbitrary_precision")]
+impl<'de> de::MapAccess<'de> for RawNumberAccess {
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>>
+    where
+        K: de::DeserializeSeed<'de>,
+    {
+        if self.raw.is_none() {
+            return Ok(None);
+        }
+        seed.deserialize(RawNumberKeyDeserializer).map(Some)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        let raw = self.raw.take().expect("next_value_seed called before next_key_seed");
+        seed.deserialize(RawNumberValueDeserializer(raw))
+    }
+}
+
+#[cfg(feature = "arbitrary_precision")]
+struct RawNumberKeyDeserializer;
+
+#[cfg(feature = "arbitrary_precision")]
+impl<'de> de::Deserializer<'de> for RawNumberKeyDeserializer {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_borrowed_str(TOKEN)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char str string bytes
+        byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+#[cfg(feature = "arbitrary_precision")]
+struct RawNumberValueDeserializer(String);
+
+#[cfg(feature = "arbitrary_precision")]
+impl<'de> de::Deserializer<'de> for RawNumberValueDeserializer {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_string(self.0)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char str string bytes
+        byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+impl<'de, R: Read<'de>, F: Dialect> Deserializer<R, F> {
     /// The `Deserializer::end` method should be called after a value has been fully deserialized.
     /// This allows the `Deserializer` to validate that the input stream is at the end or that it
     /// only has trailing whitespace.
@@ -121,7 +819,7 @@ impl<'de, R: Read<'de>> Deserializer<R> {
     }
 
     /// Turn a JSON deserializer into an iterator over values of type T.
-    pub fn into_iter<T>(self) -> StreamDeserializer<'de, R, T>
+    pub fn into_iter<T>(self) -> StreamDeserializer<'de, R, T, F>
     where
         T: de::Deserialize<'de>,
     {
@@ -131,6 +829,12 @@ impl<'de, R: Read<'de>> Deserializer<R> {
         StreamDeserializer {
             de: self,
             offset: offset,
+            line_delimited: false,
+            strict_lines: false,
+            recover: false,
+            closer: None,
+            expect_value: false,
+            done: false,
             output: PhantomData,
             lifetime: PhantomData,
         }
@@ -144,6 +848,73 @@ impl<'de, R: Read<'de>> Deserializer<R> {
         Ok(try!(self.peek()).unwrap_or(b'\x00'))
     }
 
+    fn single_quotes_allowed(&self) -> bool {
+        F::allow_single_quotes() || self.config.allow_single_quotes
+    }
+
+    fn unquoted_keys_allowed(&self) -> bool {
+        F::allow_unquoted_keys() || self.config.allow_unquoted_keys
+    }
+
+    fn unquoted_values_allowed(&self) -> bool {
+        F::allow_unquoted_values() || self.config.allow_unquoted_values
+    }
+
+    fn newline_separators_allowed(&self) -> bool {
+        F::newline_terminates_value() || self.config.allow_newline_separators
+    }
+
+    fn trailing_commas_allowed(&self) -> bool {
+        F::allow_trailing_commas() || self.config.allow_trailing_commas
+    }
+
+    fn line_comments_allowed(&self) -> bool {
+        F::allow_line_comments() || self.config.allow_line_comments
+    }
+
+    fn block_comments_allowed(&self) -> bool {
+        F::allow_block_comments() || self.config.allow_block_comments
+    }
+
+    /// Whether `#` starts a line comment. Always `false` when the dialect
+    /// uses `#` for atom escapes instead (`F::hash_escapes()`), regardless
+    /// of the runtime config.
+    fn hash_comments_allowed(&self) -> bool {
+        !F::hash_escapes() && (F::hash_is_comment() || self.config.allow_hash_comments)
+    }
+
+    /// How string content is decoded by `deserialize_bytes`/`deserialize_byte_buf`.
+    /// No `Dialect` has an opinion on this, so it's taken from the runtime
+    /// config alone.
+    fn bytes_encoding(&self) -> BytesEncoding {
+        self.config.bytes_encoding
+    }
+
+    /// Parses an unquoted/bareword token, or reports a syntax error if
+    /// unquoted strings aren't allowed in this position.
+    fn parse_none_str<'s>(&'s mut self, is_key: bool) -> Result<Reference<'de, 's, str>> {
+        let allowed = if is_key {
+            self.unquoted_keys_allowed()
+        } else {
+            self.unquoted_values_allowed()
+        };
+        if !allowed {
+            return Err(self.peek_error(ErrorCode::ExpectedSomeValue));
+        }
+
+        self.read.parse_none_str(&mut self.str_buf)
+    }
+
+    /// Skips an unquoted/bareword value, or reports a syntax error if
+    /// unquoted values aren't allowed.
+    fn ignore_none_str(&mut self) -> Result<()> {
+        if !self.unquoted_values_allowed() {
+            return Err(self.peek_error(ErrorCode::ExpectedSomeValue));
+        }
+
+        self.read.ignore_none_str()
+    }
+
     fn eat_char(&mut self) {
         if let Ok(Some(c)) = self.next_char() {
             if self.capture {
@@ -229,17 +1000,17 @@ impl<'de, R: Read<'de>> Deserializer<R> {
             } else {
                 match try!(self.peek()) {
                     Some(b' ') | Some(b'\n') | Some(b'\t') | Some(b'\r') => {}
-                    Some(b'#') => {
+                    Some(b'#') if self.hash_comments_allowed() => {
                         line_comment = true;
                     }
-                    Some(b'/') => {
+                    Some(b'/') if self.line_comments_allowed() || self.block_comments_allowed() => {
                         self.eat_char();
 
                         match try!(self.peek()) {
-                            Some(b'/') => {
+                            Some(b'/') if self.line_comments_allowed() => {
                                 line_comment = true;
                             }
-                            Some(b'*') => {
+                            Some(b'*') if self.block_comments_allowed() => {
                                 multiline_comment = true;
                             }
                             other => {
@@ -298,17 +1069,19 @@ impl<'de, R: Read<'de>> Deserializer<R> {
                 match try!(self.peek()) {
                     Some(b' ') | Some(b'\t') => {},
                     Some(b'\n') | Some(b'\r') => {
-                        *had_newline = true;
+                        if self.newline_separators_allowed() {
+                            *had_newline = true;
+                        }
                     }
-                    Some(b'#') => {
+                    Some(b'#') if self.hash_comments_allowed() => {
                         line_comment = true;
                     }
-                    Some(b'/') => {
+                    Some(b'/') if self.line_comments_allowed() || self.block_comments_allowed() => {
                         match try!(self.peek()) {
-                            Some(b'/') => {
+                            Some(b'/') if self.line_comments_allowed() => {
                                 line_comment = true;
                             }
-                            Some(b'*') => {
+                            Some(b'*') if self.block_comments_allowed() => {
                                 multiline_comment = true;
                             }
                             other => {
@@ -372,19 +1145,21 @@ impl<'de, R: Read<'de>> Deserializer<R> {
             } else {
                 match try!(self.peek()) {
                     Some(b' ') | Some(b'\t') => {},
+                    // A newline always ends the value; whether it may stand in
+                    // for a comma is checked by `parse_whitespace_get_newline`.
                     Some(b'\n') | Some(b'\r') => {
                         *had_newline = true;
                         return Ok(prev);
                     }
-                    Some(b'#') => {
+                    Some(b'#') if self.hash_comments_allowed() => {
                         line_comment = true;
                     }
-                    Some(b'/') => {
+                    Some(b'/') if self.line_comments_allowed() || self.block_comments_allowed() => {
                         match try!(self.peek()) {
-                            Some(b'/') => {
+                            Some(b'/') if self.line_comments_allowed() => {
                                 line_comment = true;
                             }
-                            Some(b'*') => {
+                            Some(b'*') if self.block_comments_allowed() => {
                                 multiline_comment = true;
                             }
                             other => {
@@ -452,7 +1227,7 @@ impl<'de, R: Read<'de>> Deserializer<R> {
                     Err(err) => return err,
                 }
             }
-            b'\'' => {
+            b'\'' if self.single_quotes_allowed() => {
                 self.eat_char();
                 self.str_buf.clear();
                 match self.read.parse_single_str(&mut self.str_buf) {
@@ -492,7 +1267,7 @@ impl<'de, R: Read<'de>> Deserializer<R> {
             b'0'...b'9' => try!(self.parse_integer(true)).visit(visitor),
             _ => {
                 self.str_buf.clear();
-                match try!(self.read.parse_none_str(&mut self.str_buf)) {
+                match try!(self.parse_none_str(false)) {
                     Reference::Borrowed(s) => visitor.visit_borrowed_str(s),
                     Reference::Copied(s) => visitor.visit_str(s),
                 }
@@ -505,11 +1280,113 @@ impl<'de, R: Read<'de>> Deserializer<R> {
         }
     }
 
+    /// Parses the integer/float literal starting at the current position
+    /// and hands it to `visitor`, falling back to treating it as an
+    /// unquoted bareword if the dialect allows unquoted values and what
+    /// follows isn't valid number syntax. The caller must already have set
+    /// `self.capture` so the exact digits land in `str_buf` as they're
+    /// consumed.
+    fn parse_number_leaf<V>(&mut self, visitor: V, positive: bool) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        match self.parse_integer(positive) {
+            Ok(num) => self.visit_number(num, visitor),
+            Err(_) => {
+                let captured_num = unsafe { String::from_utf8_unchecked(self.str_buf.clone()) };
+
+                self.capture = false;
+                self.str_buf.clear();
+                match try!(self.parse_none_str(false)) {
+                    Reference::Borrowed(s) => visitor.visit_str(&(captured_num + s)),
+                    Reference::Copied(s) => visitor.visit_str(&(captured_num + &s)),
+                }
+            }
+        }
+    }
+
+    /// Delivers a successfully parsed number to `visitor`.
+    ///
+    /// Without the `arbitrary_precision` feature this just rounds it into
+    /// whichever of `f64`/`u64`/`i64` `num` already is. With the feature
+    /// enabled, the exact digits captured into `str_buf` while `num` was
+    /// parsed are handed to the visitor as a [`RawNumber`] instead, so no
+    /// precision is lost.
+    #[cfg(not(feature = "arbitrary_precision"))]
+    fn visit_number<V>(&mut self, num: Number, visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        num.visit(visitor)
+    }
+
+    #[cfg(feature = "arbitrary_precision")]
+    fn visit_number<V>(&mut self, _num: Number, visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        let raw = unsafe { String::from_utf8_unchecked(self.str_buf.clone()) };
+        visitor.visit_map(RawNumberAccess::new(raw))
+    }
+
     #[cold]
     fn fix_position(&self, err: Error) -> Error {
         err.fix_position(move |code| self.error(code))
     }
 
+    /// Hands a string's raw bytes to the visitor, or -- when
+    /// `self.bytes_encoding()` requests it -- decodes them as base64/base16
+    /// text first.
+    ///
+    /// A bad encoding reports `de::Error::custom` ("invalid base64 string" or
+    /// "invalid base16 string"), positioned via `fix_position`; there is no
+    /// dedicated `ErrorCode` variant for it.
+    fn visit_encoded_bytes<'s, V>(
+        &mut self,
+        raw: Reference<'de, 's, [u8]>,
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        match self.bytes_encoding() {
+            BytesEncoding::Raw => match raw {
+                Reference::Borrowed(b) => visitor.visit_borrowed_bytes(b),
+                Reference::Copied(b) => visitor.visit_bytes(b),
+            },
+            BytesEncoding::Base64 => {
+                let bytes = match raw {
+                    Reference::Borrowed(b) => b,
+                    Reference::Copied(b) => b,
+                };
+                match decode_base64(bytes, false) {
+                    Some(decoded) => visitor.visit_byte_buf(decoded),
+                    None => Err(self.fix_position(de::Error::custom("invalid base64 string"))),
+                }
+            }
+            BytesEncoding::Base64Url => {
+                let bytes = match raw {
+                    Reference::Borrowed(b) => b,
+                    Reference::Copied(b) => b,
+                };
+                match decode_base64(bytes, true) {
+                    Some(decoded) => visitor.visit_byte_buf(decoded),
+                    None => Err(self.fix_position(de::Error::custom("invalid base64 string"))),
+                }
+            }
+            BytesEncoding::Base16 => {
+                let bytes = match raw {
+                    Reference::Borrowed(b) => b,
+                    Reference::Copied(b) => b,
+                };
+                match decode_base16(bytes) {
+                    Some(decoded) => visitor.visit_byte_buf(decoded),
+                    None => Err(self.fix_position(de::Error::custom("invalid base16 string"))),
+                }
+            }
+        }
+    }
+
     fn parse_ident(&mut self, ident: &[u8]) -> Result<()> {
         debug!(parse_ident);
         for c in ident {
@@ -530,11 +1407,31 @@ impl<'de, R: Read<'de>> Deserializer<R> {
                 Some(b',') |
                 Some(b']') |
                 Some(b'}') => Ok(()),
+                Some(b')') if F::parens_are_seq() => Ok(()),
                 _ => Err(self.error(ErrorCode::UnexpectedCharacter)),
             }
         }
     }
 
+    /// Parses the `#t`/`#f`/`#nil`/`#\x` atom escapes used by the
+    /// S-expression dialect, where `#` would otherwise start a line comment.
+    ///
+    /// `#\x` only covers a single ASCII byte; named characters like
+    /// `#\space`/`#\newline` aren't recognized.
+    fn ignore_hash_escape(&mut self) -> Result<()> {
+        debug!(ignore_hash_escape);
+        self.eat_char();
+        match try!(self.next_char_or_null()) {
+            b't' | b'f' => Ok(()),
+            b'n' => self.parse_ident(b"il"),
+            b'\\' => {
+                try!(self.next_char_or_null());
+                Ok(())
+            }
+            _ => Err(self.error(ErrorCode::ExpectedSomeValue)),
+        }
+    }
+
     fn parse_integer(&mut self, pos: bool) -> Result<Number> {
         debug!(parse_integer);
         match try!(self.peek_or_null()) {
@@ -802,18 +1699,18 @@ impl<'de, R: Read<'de>> Deserializer<R> {
         }
     }
 
-    fn end_seq(&mut self) -> Result<()> {
+    fn end_seq(&mut self, closer: u8) -> Result<()> {
         debug!(end_seq);
         let ch = try!(self.parse_whitespace());
         match ch {
-            Some(b']') => {
+            Some(b) if b == closer => {
                 self.eat_char();
                 Ok(())
             }
             Some(b',') => {
                 self.eat_char();
                 match self.parse_whitespace() {
-                    Ok(Some(b']')) => Err(self.peek_error(ErrorCode::ExtraComma)),
+                    Ok(Some(b)) if b == closer => Err(self.peek_error(ErrorCode::ExtraComma)),
                     _ => Err(self.peek_error(ErrorCode::TrailingCharacters)), // This shouldn't be possible
                 }
             }
@@ -868,26 +1765,40 @@ impl<'de, R: Read<'de>> Deserializer<R> {
                 self.eat_char();
                 self.read.ignore_double_str()
             }
-            b'\'' => {
+            b'\'' if self.single_quotes_allowed() => {
                 self.eat_char();
                 self.read.ignore_single_str()
             }
+            b'#' if F::hash_escapes() => {
+                self.ignore_hash_escape()
+            }
             b'[' => {
+                if self.remaining_depth == 0 {
+                    return Err(self.peek_error(ErrorCode::RecursionLimitExceeded));
+                }
                 self.remaining_depth -= 1;
+
+                self.eat_char();
+                let res = self.ignore_seq(b']');
+                self.remaining_depth += 1;
+                res
+            }
+            b'(' if F::parens_are_seq() => {
                 if self.remaining_depth == 0 {
                     return Err(self.peek_error(ErrorCode::RecursionLimitExceeded));
                 }
+                self.remaining_depth -= 1;
 
                 self.eat_char();
-                let res = self.ignore_seq();
+                let res = self.ignore_seq(b')');
                 self.remaining_depth += 1;
                 res
             }
             b'{' => {
-                self.remaining_depth -= 1;
                 if self.remaining_depth == 0 {
                     return Err(self.peek_error(ErrorCode::RecursionLimitExceeded));
                 }
+                self.remaining_depth -= 1;
 
                 self.eat_char();
                 let res = self.ignore_map();
@@ -895,7 +1806,7 @@ impl<'de, R: Read<'de>> Deserializer<R> {
                 res
             }
             _ => {
-                self.read.ignore_none_str()
+                self.ignore_none_str()
             }
         }
     }
@@ -970,13 +1881,17 @@ impl<'de, R: Read<'de>> Deserializer<R> {
         Ok(())
     }
 
-    fn ignore_seq(&mut self) -> Result<()> {
+    fn ignore_seq(&mut self, closer: u8) -> Result<()> {
         debug!(ignore_seq);
         let mut had_newline;
+        let mut expect_value = false;
 
         loop {
             match try!(self.parse_whitespace()) {
-                Some(b']') => {
+                Some(b) if b == closer => {
+                    if expect_value && !self.trailing_commas_allowed() {
+                        return Err(self.peek_error(ErrorCode::ExtraComma));
+                    }
                     self.eat_char();
                     return Ok(());
                 }
@@ -987,13 +1902,15 @@ impl<'de, R: Read<'de>> Deserializer<R> {
             }
 
             try!(self.ignore_value());
+            expect_value = false;
 
             had_newline = false;
             match try!(self.parse_whitespace_get_newline(&mut had_newline)) {
                 Some(ch) => {
                     if ch == b',' {
                         self.eat_char();
-                    } else if ch != b']' && !had_newline {
+                        expect_value = true;
+                    } else if ch != closer && !had_newline && closer != b')' {
                         return Err(self.peek_error(ErrorCode::ExpectedListCommaOrEnd));
                     }
                 }
@@ -1007,10 +1924,14 @@ impl<'de, R: Read<'de>> Deserializer<R> {
     fn ignore_map(&mut self) -> Result<()> {
         debug!(ignore_map);
         let mut had_newline;
+        let mut expect_value = false;
 
         loop {
             match try!(self.parse_whitespace()) {
                 Some(b'}') => {
+                    if expect_value && !self.trailing_commas_allowed() {
+                        return Err(self.peek_error(ErrorCode::ExtraComma));
+                    }
                     self.eat_char();
                     return Ok(());
                 }
@@ -1025,11 +1946,14 @@ impl<'de, R: Read<'de>> Deserializer<R> {
                     self.eat_char();
                     try!(self.read.ignore_double_str());
                 }
-                Some(b'\'') => {
+                Some(b'\'') if self.single_quotes_allowed() => {
                     self.eat_char();
                     try!(self.read.ignore_single_str());
                 }
                 Some(_) => {
+                    if !self.unquoted_keys_allowed() {
+                        return Err(self.peek_error(ErrorCode::ExpectedSomeValue));
+                    }
                     try!(self.read.ignore_member_name());
                 }
                 None => {
@@ -1050,12 +1974,14 @@ impl<'de, R: Read<'de>> Deserializer<R> {
             }
 
             try!(self.ignore_value());
+            expect_value = false;
 
             had_newline = false;
             match try!(self.parse_whitespace_get_newline(&mut had_newline)) {
                 Some(ch) => {
                     if ch == b',' {
                         self.eat_char();
+                        expect_value = true;
                     } else if ch != b'}' && !had_newline {
                         return Err(self.peek_error(ErrorCode::ExpectedListCommaOrEnd));
                     }
@@ -1066,6 +1992,297 @@ impl<'de, R: Read<'de>> Deserializer<R> {
             }
         }
     }
+
+    /// The line/column/byte-offset location of a point in the input, as
+    /// reported by the underlying `Read`. `pos` must have been obtained from
+    /// `self.read` with no intervening read, since `byte_offset()` is
+    /// re-queried here and has to describe the same point `pos` does.
+    fn source_pos(&self, pos: read::Position) -> SourcePos {
+        SourcePos {
+            byte_offset: self.read.byte_offset(),
+            line: pos.line,
+            column: pos.column,
+        }
+    }
+
+    /// Deserializes a `T`, recording the `[start, end)` span of input bytes
+    /// it was parsed from. `start` is taken right after leading whitespace
+    /// and comments are skipped; `end` right after the value is fully
+    /// parsed.
+    pub fn deserialize_spanned<T>(&mut self) -> Result<Spanned<T>>
+    where
+        T: de::Deserialize<'de>,
+    {
+        try!(self.parse_whitespace());
+        let start_pos = self.read.peek_position();
+        let start = self.source_pos(start_pos);
+
+        let value = try!(T::deserialize(&mut *self));
+
+        // `peek_position()`, not `position()`: the latter is the (inclusive)
+        // position of the last consumed byte, which disagrees with the
+        // (exclusive) `byte_offset()` `source_pos` pairs it with whenever
+        // that byte was a newline.
+        let end_pos = self.read.peek_position();
+        let end = self.source_pos(end_pos);
+
+        Ok(Spanned {
+            value: value,
+            start: start,
+            end: end,
+        })
+    }
+
+    /// Parses forward through the document following a JSON-Pointer-style
+    /// `pointer` (e.g. `"/results"` or `"/a/b/0"`, per RFC 6901), stopping
+    /// once it reaches the array at that path and consuming its opening
+    /// `[`. The returned [`StreamDeserializer`] yields each of the array's
+    /// elements via the usual iterator machinery, treating the matching
+    /// `]` as the end of the stream rather than a trailing-characters
+    /// error.
+    ///
+    /// This is the constant-memory way to stream the elements of an array
+    /// buried inside a larger document, e.g.
+    /// `{"meta": {...}, "results": [ ...millions of objects... ]}`.
+    ///
+    /// Returns an error if any segment of `pointer` doesn't resolve (a
+    /// missing object member, an out-of-bounds array index, or a scalar
+    /// where an object/array was expected), or if the value at `pointer`
+    /// isn't an array.
+    ///
+    /// ```rust
+    /// use serde_json::Deserializer;
+    ///
+    /// let data = br#"{"meta": {"ok": true}, "results": [1, 2, 3]}"#;
+    ///
+    /// let de = Deserializer::from_slice(data);
+    /// let stream = de.into_iter_at::<i32>("/results").unwrap();
+    /// let results: Result<Vec<i32>, _> = stream.collect();
+    /// assert_eq!(vec![1, 2, 3], results.unwrap());
+    /// ```
+    pub fn into_iter_at<T>(mut self, pointer: &str) -> Result<StreamDeserializer<'de, R, T, F>>
+    where
+        T: de::Deserialize<'de>,
+    {
+        try!(self.seek_to_pointer(pointer));
+
+        match try!(self.parse_whitespace()) {
+            Some(b'[') => {
+                self.eat_char();
+            }
+            Some(_) => {
+                return Err(de::Error::custom("JSON pointer does not resolve to an array"));
+            }
+            None => {
+                return Err(self.peek_error(ErrorCode::EofWhileParsingValue));
+            }
+        }
+
+        let offset = self.read.byte_offset();
+        Ok(StreamDeserializer {
+            de: self,
+            offset: offset,
+            line_delimited: false,
+            strict_lines: false,
+            recover: false,
+            closer: Some(b']'),
+            expect_value: false,
+            done: false,
+            output: PhantomData,
+            lifetime: PhantomData,
+        })
+    }
+
+    /// Parses forward through each segment of a JSON-Pointer-style path,
+    /// descending into the object member or array element it names and
+    /// leaving the cursor positioned right at the start of that value.
+    fn seek_to_pointer(&mut self, pointer: &str) -> Result<()> {
+        if pointer.is_empty() {
+            return Ok(());
+        }
+        if !pointer.starts_with('/') {
+            return Err(de::Error::custom("JSON pointer must be empty or start with '/'"));
+        }
+
+        for raw_segment in pointer[1..].split('/') {
+            let segment = unescape_pointer_segment(raw_segment);
+
+            match try!(self.parse_whitespace()) {
+                Some(b'{') => {
+                    self.eat_char();
+                    try!(self.seek_to_object_key(&segment));
+                }
+                Some(b'[') => {
+                    self.eat_char();
+                    let index = try!(
+                        segment
+                            .parse::<usize>()
+                            .map_err(|_| de::Error::custom(
+                                "JSON pointer segment is not a valid array index"
+                            ))
+                    );
+                    try!(self.seek_to_array_index(index));
+                }
+                Some(_) => {
+                    return Err(de::Error::custom(
+                        "JSON pointer segment does not resolve to an object or array",
+                    ));
+                }
+                None => {
+                    return Err(self.peek_error(ErrorCode::EofWhileParsingValue));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Having just consumed an object's opening `{`, skips members until
+    /// `target` is found, leaving the cursor right after its `:`.
+    fn seek_to_object_key(&mut self, target: &str) -> Result<()> {
+        loop {
+            match try!(self.parse_whitespace()) {
+                Some(b'}') | None => {
+                    return Err(de::Error::custom(
+                        "JSON pointer references a missing object member",
+                    ));
+                }
+                Some(b',') => {
+                    return Err(self.peek_error(ErrorCode::ExtraComma));
+                }
+                _ => (),
+            }
+
+            let key: String = try!(de::Deserialize::deserialize(MapKey { de: &mut *self }));
+
+            match try!(self.parse_whitespace()) {
+                Some(b':') => {
+                    self.eat_char();
+                }
+                Some(_) => {
+                    return Err(self.peek_error(ErrorCode::ExpectedColon));
+                }
+                None => {
+                    return Err(self.peek_error(ErrorCode::EofWhileParsingObject));
+                }
+            }
+
+            if key == target {
+                return Ok(());
+            }
+
+            try!(self.ignore_value());
+
+            let mut had_newline = false;
+            match try!(self.parse_whitespace_get_newline(&mut had_newline)) {
+                Some(b',') => {
+                    self.eat_char();
+                }
+                Some(b'}') => {
+                    return Err(de::Error::custom(
+                        "JSON pointer references a missing object member",
+                    ));
+                }
+                Some(_) if had_newline => (),
+                Some(_) => {
+                    return Err(self.peek_error(ErrorCode::ExpectedListCommaOrEnd));
+                }
+                None => {
+                    return Err(self.peek_error(ErrorCode::EofWhileParsingList));
+                }
+            }
+        }
+    }
+
+    /// Having just consumed an array's opening `[`, skips elements until
+    /// the `index`-th one, leaving the cursor right at its start.
+    fn seek_to_array_index(&mut self, index: usize) -> Result<()> {
+        for _ in 0..index {
+            if let Some(b']') = try!(self.parse_whitespace()) {
+                return Err(de::Error::custom("JSON pointer index is out of bounds"));
+            }
+
+            try!(self.ignore_value());
+
+            let mut had_newline = false;
+            match try!(self.parse_whitespace_get_newline(&mut had_newline)) {
+                Some(b',') => {
+                    self.eat_char();
+                }
+                Some(b']') => {
+                    return Err(de::Error::custom("JSON pointer index is out of bounds"));
+                }
+                Some(_) if had_newline => (),
+                Some(_) => {
+                    return Err(self.peek_error(ErrorCode::ExpectedListCommaOrEnd));
+                }
+                None => {
+                    return Err(self.peek_error(ErrorCode::EofWhileParsingList));
+                }
+            }
+        }
+
+        match try!(self.parse_whitespace()) {
+            Some(b']') => Err(de::Error::custom("JSON pointer index is out of bounds")),
+            Some(_) => Ok(()),
+            None => Err(self.peek_error(ErrorCode::EofWhileParsingValue)),
+        }
+    }
+}
+
+/// Decodes the `~1`/`~0` escapes in one JSON Pointer (RFC 6901) segment.
+fn unescape_pointer_segment(segment: &str) -> String {
+    if !segment.contains('~') {
+        return segment.to_owned();
+    }
+
+    let mut result = String::with_capacity(segment.len());
+    let mut chars = segment.chars();
+    while let Some(c) = chars.next() {
+        if c != '~' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('1') => result.push('/'),
+            Some('0') => result.push('~'),
+            Some(other) => {
+                result.push('~');
+                result.push(other);
+            }
+            None => result.push('~'),
+        }
+    }
+
+    result
+}
+
+/// A line, column and byte offset into the original input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SourcePos {
+    pub byte_offset: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+/// A deserialized value together with the `[start, end)` span of input it
+/// was parsed from.
+///
+/// Use [`Deserializer::deserialize_spanned`] to decode a top-level value and
+/// recover its span. This is a permanent, by-design limit of the feature,
+/// not a gap to be closed later: `serde`'s generic `Deserializer`,
+/// `SeqAccess`, and `MapAccess` traits expose no position information to an
+/// arbitrary `Visitor`/`DeserializeSeed`, so a nested `Spanned<T>` field
+/// would need either `T: IntoDeserializer` or downcasting a generic
+/// `DeserializeSeed::Value` to a concrete `Spanned<T>` -- neither of which
+/// is available in safe, generic Rust. Spans stay reachable only through
+/// this crate's own concrete `Deserializer` type, at the top level.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Spanned<T> {
+    pub value: T,
+    pub start: SourcePos,
+    pub end: SourcePos,
 }
 
 #[cfg_attr(rustfmt, rustfmt_skip)]
@@ -1102,7 +2319,7 @@ static POW10: [f64; 309] =
      1e290, 1e291, 1e292, 1e293, 1e294, 1e295, 1e296, 1e297, 1e298, 1e299,
      1e300, 1e301, 1e302, 1e303, 1e304, 1e305, 1e306, 1e307, 1e308];
 
-impl<'de, 'a, R: Read<'de>> de::Deserializer<'de> for &'a mut Deserializer<R> {
+impl<'de, 'a, R: Read<'de>, F: Dialect> de::Deserializer<'de> for &'a mut Deserializer<R, F> {
     type Error = Error;
 
     #[inline]
@@ -1125,7 +2342,7 @@ impl<'de, 'a, R: Read<'de>> de::Deserializer<'de> for &'a mut Deserializer<R> {
                     Ok(_) => visitor.visit_unit(),
                     Err(_) => {
                         self.str_buf.clear();
-                        match try!(self.read.parse_none_str(&mut self.str_buf)) {
+                        match try!(self.parse_none_str(false)) {
                             // Can't treat the original string as borrowed anymore
                             Reference::Borrowed(s) => visitor.visit_str(&("null".to_owned() + s)),
                             Reference::Copied(s) => visitor.visit_str(&("null".to_owned() + &s)),
@@ -1139,7 +2356,7 @@ impl<'de, 'a, R: Read<'de>> de::Deserializer<'de> for &'a mut Deserializer<R> {
                     Ok(_) => visitor.visit_bool(true),
                     Err(_) => {
                         self.str_buf.clear();
-                        match try!(self.read.parse_none_str(&mut self.str_buf)) {
+                        match try!(self.parse_none_str(false)) {
                             // Can't treat the original string as borrowed anymore
                             Reference::Borrowed(s) => visitor.visit_str(&("true".to_owned() + s)),
                             Reference::Copied(s) => visitor.visit_str(&("true".to_owned() + &s)),
@@ -1153,7 +2370,7 @@ impl<'de, 'a, R: Read<'de>> de::Deserializer<'de> for &'a mut Deserializer<R> {
                     Ok(_) => visitor.visit_bool(false),
                     Err(_) => {
                         self.str_buf.clear();
-                        match try!(self.read.parse_none_str(&mut self.str_buf)) {
+                        match try!(self.parse_none_str(false)) {
                             // Can't treat the original string as borrowed anymore
                             Reference::Borrowed(s) => visitor.visit_str(&("false".to_owned() + s)),
                             Reference::Copied(s) => visitor.visit_str(&("false".to_owned() + &s)),
@@ -1165,20 +2382,8 @@ impl<'de, 'a, R: Read<'de>> de::Deserializer<'de> for &'a mut Deserializer<R> {
                 self.str_buf.clear();
                 self.capture = true;
 
-                self.eat_char();
-                let ret = match self.parse_integer(false) {
-                    Ok(num) => num.visit(visitor),
-                    Err(_) => {
-                        let captured_num = unsafe { String::from_utf8_unchecked(self.str_buf.clone()) };
-
-                        self.capture = false;
-                        self.str_buf.clear();
-                        match try!(self.read.parse_none_str(&mut self.str_buf)) {
-                            Reference::Borrowed(s) => visitor.visit_str(&(captured_num + s)),
-                            Reference::Copied(s) => visitor.visit_str(&(captured_num + &s)),
-                        }
-                    }
-                };
+                self.eat_char();
+                let ret = self.parse_number_leaf(visitor, false);
 
                 self.capture = false;
 
@@ -1188,19 +2393,7 @@ impl<'de, 'a, R: Read<'de>> de::Deserializer<'de> for &'a mut Deserializer<R> {
                 self.str_buf.clear();
                 self.capture = true;
 
-                let ret = match self.parse_integer(true) {
-                    Ok(num) => num.visit(visitor),
-                    Err(_) => {
-                        let captured_num = unsafe { String::from_utf8_unchecked(self.str_buf.clone()) };
-
-                        self.capture = false;
-                        self.str_buf.clear();
-                        match try!(self.read.parse_none_str(&mut self.str_buf)) {
-                            Reference::Borrowed(s) => visitor.visit_str(&(captured_num + s)),
-                            Reference::Copied(s) => visitor.visit_str(&(captured_num + &s)),
-                        }
-                    }
-                };
+                let ret = self.parse_number_leaf(visitor, true);
 
                 self.capture = false;
 
@@ -1214,7 +2407,7 @@ impl<'de, 'a, R: Read<'de>> de::Deserializer<'de> for &'a mut Deserializer<R> {
                     Reference::Copied(s) => visitor.visit_str(s),
                 }
             }
-            b'\'' => {
+            b'\'' if self.single_quotes_allowed() => {
                 self.eat_char();
                 self.str_buf.clear();
                 match try!(self.read.parse_single_str(&mut self.str_buf)) {
@@ -1223,26 +2416,72 @@ impl<'de, 'a, R: Read<'de>> de::Deserializer<'de> for &'a mut Deserializer<R> {
                 }
             }
             b'[' => {
-                self.remaining_depth -= 1;
                 if self.remaining_depth == 0 {
                     return Err(self.peek_error(ErrorCode::RecursionLimitExceeded));
                 }
+                self.remaining_depth -= 1;
 
                 self.eat_char();
-                let ret = visitor.visit_seq(SeqAccess::new(self));
+                let ret = visitor.visit_seq(SeqAccess::new(self, b']'));
 
                 self.remaining_depth += 1;
 
-                match (ret, self.end_seq()) {
+                match (ret, self.end_seq(b']')) {
                     (Ok(ret), Ok(())) => Ok(ret),
                     (Err(err), _) | (_, Err(err)) => Err(err),
                 }
             }
+            b'(' if F::parens_are_seq() => {
+                self.eat_char();
+
+                // `()` is the S-expression unit literal; anything else opens
+                // a sequence that closes on the matching `)`.
+                match try!(self.parse_whitespace()) {
+                    Some(b')') => {
+                        self.eat_char();
+                        visitor.visit_unit()
+                    }
+                    _ => {
+                        if self.remaining_depth == 0 {
+                            return Err(self.peek_error(ErrorCode::RecursionLimitExceeded));
+                        }
+                        self.remaining_depth -= 1;
+
+                        let ret = visitor.visit_seq(SeqAccess::new(self, b')'));
+
+                        self.remaining_depth += 1;
+
+                        match (ret, self.end_seq(b')')) {
+                            (Ok(ret), Ok(())) => Ok(ret),
+                            (Err(err), _) | (_, Err(err)) => Err(err),
+                        }
+                    }
+                }
+            }
+            b'#' if F::hash_escapes() => {
+                self.eat_char();
+                match try!(self.next_char_or_null()) {
+                    b't' => visitor.visit_bool(true),
+                    b'f' => visitor.visit_bool(false),
+                    b'n' => {
+                        try!(self.parse_ident(b"il"));
+                        visitor.visit_unit()
+                    }
+                    // `#\x` only covers a single ASCII byte; named
+                    // characters like `#\space`/`#\newline` aren't
+                    // recognized.
+                    b'\\' => {
+                        let ch = try!(self.next_char_or_null());
+                        visitor.visit_char(ch as char)
+                    }
+                    _ => Err(self.error(ErrorCode::ExpectedSomeValue)),
+                }
+            }
             b'{' => {
-                self.remaining_depth -= 1;
                 if self.remaining_depth == 0 {
                     return Err(self.peek_error(ErrorCode::RecursionLimitExceeded));
                 }
+                self.remaining_depth -= 1;
 
                 self.eat_char();
                 let ret = visitor.visit_map(MapAccess::new(self));
@@ -1256,7 +2495,7 @@ impl<'de, 'a, R: Read<'de>> de::Deserializer<'de> for &'a mut Deserializer<R> {
             }
             _ => {
                 self.str_buf.clear();
-                match try!(self.read.parse_none_str(&mut self.str_buf)) {
+                match try!(self.parse_none_str(false)) {
                     Reference::Borrowed(s) => visitor.visit_borrowed_str(s),
                     Reference::Copied(s) => visitor.visit_str(s),
                 }
@@ -1404,7 +2643,7 @@ impl<'de, 'a, R: Read<'de>> de::Deserializer<'de> for &'a mut Deserializer<R> {
                     Reference::Copied(s) => visitor.visit_str(s),
                 }
             }
-            b'\'' => {
+            b'\'' if self.single_quotes_allowed() => {
                 self.eat_char();
                 self.str_buf.clear();
                 match try!(self.read.parse_single_str(&mut self.str_buf)) {
@@ -1525,20 +2764,17 @@ impl<'de, 'a, R: Read<'de>> de::Deserializer<'de> for &'a mut Deserializer<R> {
             b'"' => {
                 self.eat_char();
                 self.str_buf.clear();
-                match try!(self.read.parse_double_str_raw(&mut self.str_buf)) {
-                    Reference::Borrowed(b) => visitor.visit_borrowed_bytes(b),
-                    Reference::Copied(b) => visitor.visit_bytes(b),
-                }
+                let raw = try!(self.read.parse_double_str_raw(&mut self.str_buf));
+                self.visit_encoded_bytes(raw, visitor)
             }
-            b'\'' => {
+            b'\'' if self.single_quotes_allowed() => {
                 self.eat_char();
                 self.str_buf.clear();
-                match try!(self.read.parse_single_str_raw(&mut self.str_buf)) {
-                    Reference::Borrowed(b) => visitor.visit_borrowed_bytes(b),
-                    Reference::Copied(b) => visitor.visit_bytes(b),
-                }
+                let raw = try!(self.read.parse_single_str_raw(&mut self.str_buf));
+                self.visit_encoded_bytes(raw, visitor)
             }
             b'[' => self.deserialize_seq(visitor),
+            b'(' if F::parens_are_seq() => self.deserialize_seq(visitor),
             _ => Err(self.peek_invalid_type(&visitor)),
         };
 
@@ -1634,17 +2870,33 @@ impl<'de, 'a, R: Read<'de>> de::Deserializer<'de> for &'a mut Deserializer<R> {
 
         let value = match peek {
             b'[' => {
+                if self.remaining_depth == 0 {
+                    return Err(self.peek_error(ErrorCode::RecursionLimitExceeded));
+                }
                 self.remaining_depth -= 1;
+
+                self.eat_char();
+                let ret = visitor.visit_seq(SeqAccess::new(self, b']'));
+
+                self.remaining_depth += 1;
+
+                match (ret, self.end_seq(b']')) {
+                    (Ok(ret), Ok(())) => Ok(ret),
+                    (Err(err), _) | (_, Err(err)) => Err(err),
+                }
+            }
+            b'(' if F::parens_are_seq() => {
                 if self.remaining_depth == 0 {
                     return Err(self.peek_error(ErrorCode::RecursionLimitExceeded));
                 }
+                self.remaining_depth -= 1;
 
                 self.eat_char();
-                let ret = visitor.visit_seq(SeqAccess::new(self));
+                let ret = visitor.visit_seq(SeqAccess::new(self, b')'));
 
                 self.remaining_depth += 1;
 
-                match (ret, self.end_seq()) {
+                match (ret, self.end_seq(b')')) {
                     (Ok(ret), Ok(())) => Ok(ret),
                     (Err(err), _) | (_, Err(err)) => Err(err),
                 }
@@ -1695,10 +2947,10 @@ impl<'de, 'a, R: Read<'de>> de::Deserializer<'de> for &'a mut Deserializer<R> {
 
         let value = match peek {
             b'{' => {
-                self.remaining_depth -= 1;
                 if self.remaining_depth == 0 {
                     return Err(self.peek_error(ErrorCode::RecursionLimitExceeded));
                 }
+                self.remaining_depth -= 1;
 
                 self.eat_char();
                 let ret = visitor.visit_map(MapAccess::new(self));
@@ -1738,26 +2990,42 @@ impl<'de, 'a, R: Read<'de>> de::Deserializer<'de> for &'a mut Deserializer<R> {
 
         let value = match peek {
             b'[' => {
+                if self.remaining_depth == 0 {
+                    return Err(self.peek_error(ErrorCode::RecursionLimitExceeded));
+                }
                 self.remaining_depth -= 1;
+
+                self.eat_char();
+                let ret = visitor.visit_seq(SeqAccess::new(self, b']'));
+
+                self.remaining_depth += 1;
+
+                match (ret, self.end_seq(b']')) {
+                    (Ok(ret), Ok(())) => Ok(ret),
+                    (Err(err), _) | (_, Err(err)) => Err(err),
+                }
+            }
+            b'(' if F::parens_are_seq() => {
                 if self.remaining_depth == 0 {
                     return Err(self.peek_error(ErrorCode::RecursionLimitExceeded));
                 }
+                self.remaining_depth -= 1;
 
                 self.eat_char();
-                let ret = visitor.visit_seq(SeqAccess::new(self));
+                let ret = visitor.visit_seq(SeqAccess::new(self, b')'));
 
                 self.remaining_depth += 1;
 
-                match (ret, self.end_seq()) {
+                match (ret, self.end_seq(b')')) {
                     (Ok(ret), Ok(())) => Ok(ret),
                     (Err(err), _) | (_, Err(err)) => Err(err),
                 }
             }
             b'{' => {
-                self.remaining_depth -= 1;
                 if self.remaining_depth == 0 {
                     return Err(self.peek_error(ErrorCode::RecursionLimitExceeded));
                 }
+                self.remaining_depth -= 1;
 
                 self.eat_char();
                 let ret = visitor.visit_map(MapAccess::new(self));
@@ -1793,10 +3061,10 @@ impl<'de, 'a, R: Read<'de>> de::Deserializer<'de> for &'a mut Deserializer<R> {
         debug!(deserialize_enum);
         match try!(self.parse_whitespace()) {
             Some(b'{') => {
-                self.remaining_depth -= 1;
                 if self.remaining_depth == 0 {
                     return Err(self.peek_error(ErrorCode::RecursionLimitExceeded));
                 }
+                self.remaining_depth -= 1;
 
                 self.eat_char();
                 let value = try!(visitor.visit_enum(VariantAccess::new(self)));
@@ -1838,19 +3106,23 @@ impl<'de, 'a, R: Read<'de>> de::Deserializer<'de> for &'a mut Deserializer<R> {
     }
 }
 
-struct SeqAccess<'a, R: 'a> {
-    de: &'a mut Deserializer<R>,
+struct SeqAccess<'a, R: 'a, F: 'a> {
+    de: &'a mut Deserializer<R, F>,
+    closer: u8,
+    expect_value: bool,
 }
 
-impl<'a, R: 'a> SeqAccess<'a, R> {
-    fn new(de: &'a mut Deserializer<R>) -> Self {
+impl<'a, R: 'a, F: 'a> SeqAccess<'a, R, F> {
+    fn new(de: &'a mut Deserializer<R, F>, closer: u8) -> Self {
         SeqAccess {
             de: de,
+            closer: closer,
+            expect_value: false,
         }
     }
 }
 
-impl<'de, 'a, R: Read<'de> + 'a> de::SeqAccess<'de> for SeqAccess<'a, R> {
+impl<'de, 'a, R: Read<'de> + 'a, F: Dialect + 'a> de::SeqAccess<'de> for SeqAccess<'a, R, F> {
     type Error = Error;
 
     fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>>
@@ -1859,7 +3131,10 @@ impl<'de, 'a, R: Read<'de> + 'a> de::SeqAccess<'de> for SeqAccess<'a, R> {
     {
         debug!(next_element_seed);
         match try!(self.de.parse_whitespace()) {
-            Some(b']') => {
+            Some(b) if b == self.closer => {
+                if self.expect_value && !self.de.trailing_commas_allowed() {
+                    return Err(self.de.peek_error(ErrorCode::ExtraComma));
+                }
                 return Ok(None);
             }
             Some(b',') => {
@@ -1869,13 +3144,15 @@ impl<'de, 'a, R: Read<'de> + 'a> de::SeqAccess<'de> for SeqAccess<'a, R> {
         }
 
         let ret = seed.deserialize(&mut *self.de).map(Some);
+        self.expect_value = false;
 
         let mut had_newline = false;
         match try!(self.de.parse_whitespace_get_newline(&mut had_newline)) {
             Some(ch) => {
                 if ch == b',' {
                     self.de.eat_char();
-                } else if ch != b']' && !had_newline {
+                    self.expect_value = true;
+                } else if ch != self.closer && !had_newline && self.closer != b')' {
                     return Err(self.de.peek_error(ErrorCode::ExpectedListCommaOrEnd));
                 }
             }
@@ -1888,19 +3165,21 @@ impl<'de, 'a, R: Read<'de> + 'a> de::SeqAccess<'de> for SeqAccess<'a, R> {
     }
 }
 
-struct MapAccess<'a, R: 'a> {
-    de: &'a mut Deserializer<R>,
+struct MapAccess<'a, R: 'a, F: 'a> {
+    de: &'a mut Deserializer<R, F>,
+    expect_value: bool,
 }
 
-impl<'a, R: 'a> MapAccess<'a, R> {
-    fn new(de: &'a mut Deserializer<R>) -> Self {
+impl<'a, R: 'a, F: 'a> MapAccess<'a, R, F> {
+    fn new(de: &'a mut Deserializer<R, F>) -> Self {
         MapAccess {
             de: de,
+            expect_value: false,
         }
     }
 }
 
-impl<'de, 'a, R: Read<'de> + 'a> de::MapAccess<'de> for MapAccess<'a, R> {
+impl<'de, 'a, R: Read<'de> + 'a, F: Dialect + 'a> de::MapAccess<'de> for MapAccess<'a, R, F> {
     type Error = Error;
 
     fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>>
@@ -1910,12 +3189,16 @@ impl<'de, 'a, R: Read<'de> + 'a> de::MapAccess<'de> for MapAccess<'a, R> {
         debug!(next_key_seed);
         match try!(self.de.parse_whitespace()) {
             Some(b'}') => {
+                if self.expect_value && !self.de.trailing_commas_allowed() {
+                    return Err(self.de.peek_error(ErrorCode::ExtraComma));
+                }
                 return Ok(None);
             }
             Some(b',') => {
                 return Err(self.de.peek_error(ErrorCode::ExtraComma));
             },
             Some(_) => {
+                self.expect_value = false;
                 seed.deserialize(MapKey { de: &mut *self.de }).map(Some)
             }
             None => {
@@ -1938,6 +3221,7 @@ impl<'de, 'a, R: Read<'de> + 'a> de::MapAccess<'de> for MapAccess<'a, R> {
             Some(ch) => {
                 if ch == b',' {
                     self.de.eat_char();
+                    self.expect_value = true;
                 } else if ch != b'}' && !had_newline {
                     return Err(self.de.peek_error(ErrorCode::ExpectedListCommaOrEnd));
                 }
@@ -1951,17 +3235,17 @@ impl<'de, 'a, R: Read<'de> + 'a> de::MapAccess<'de> for MapAccess<'a, R> {
     }
 }
 
-struct VariantAccess<'a, R: 'a> {
-    de: &'a mut Deserializer<R>,
+struct VariantAccess<'a, R: 'a, F: 'a> {
+    de: &'a mut Deserializer<R, F>,
 }
 
-impl<'a, R: 'a> VariantAccess<'a, R> {
-    fn new(de: &'a mut Deserializer<R>) -> Self {
+impl<'a, R: 'a, F: 'a> VariantAccess<'a, R, F> {
+    fn new(de: &'a mut Deserializer<R, F>) -> Self {
         VariantAccess { de: de }
     }
 }
 
-impl<'de, 'a, R: Read<'de> + 'a> de::EnumAccess<'de> for VariantAccess<'a, R> {
+impl<'de, 'a, R: Read<'de> + 'a, F: Dialect + 'a> de::EnumAccess<'de> for VariantAccess<'a, R, F> {
     type Error = Error;
     type Variant = Self;
 
@@ -1976,7 +3260,7 @@ impl<'de, 'a, R: Read<'de> + 'a> de::EnumAccess<'de> for VariantAccess<'a, R> {
     }
 }
 
-impl<'de, 'a, R: Read<'de> + 'a> de::VariantAccess<'de> for VariantAccess<'a, R> {
+impl<'de, 'a, R: Read<'de> + 'a, F: Dialect + 'a> de::VariantAccess<'de> for VariantAccess<'a, R, F> {
     type Error = Error;
 
     fn unit_variant(self) -> Result<()> {
@@ -2008,17 +3292,17 @@ impl<'de, 'a, R: Read<'de> + 'a> de::VariantAccess<'de> for VariantAccess<'a, R>
     }
 }
 
-struct UnitVariantAccess<'a, R: 'a> {
-    de: &'a mut Deserializer<R>,
+struct UnitVariantAccess<'a, R: 'a, F: 'a> {
+    de: &'a mut Deserializer<R, F>,
 }
 
-impl<'a, R: 'a> UnitVariantAccess<'a, R> {
-    fn new(de: &'a mut Deserializer<R>) -> Self {
+impl<'a, R: 'a, F: 'a> UnitVariantAccess<'a, R, F> {
+    fn new(de: &'a mut Deserializer<R, F>) -> Self {
         UnitVariantAccess { de: de }
     }
 }
 
-impl<'de, 'a, R: Read<'de> + 'a> de::EnumAccess<'de> for UnitVariantAccess<'a, R> {
+impl<'de, 'a, R: Read<'de> + 'a, F: Dialect + 'a> de::EnumAccess<'de> for UnitVariantAccess<'a, R, F> {
     type Error = Error;
     type Variant = Self;
 
@@ -2032,7 +3316,7 @@ impl<'de, 'a, R: Read<'de> + 'a> de::EnumAccess<'de> for UnitVariantAccess<'a, R
     }
 }
 
-impl<'de, 'a, R: Read<'de> + 'a> de::VariantAccess<'de> for UnitVariantAccess<'a, R> {
+impl<'de, 'a, R: Read<'de> + 'a, F: Dialect + 'a> de::VariantAccess<'de> for UnitVariantAccess<'a, R, F> {
     type Error = Error;
 
     fn unit_variant(self) -> Result<()> {
@@ -2063,8 +3347,8 @@ impl<'de, 'a, R: Read<'de> + 'a> de::VariantAccess<'de> for UnitVariantAccess<'a
 
 /// Only deserialize from this after peeking a '"' byte! Otherwise it may
 /// deserialize invalid JSON successfully.
-struct MapKey<'a, R: 'a> {
-    de: &'a mut Deserializer<R>,
+struct MapKey<'a, R: 'a, F: 'a> {
+    de: &'a mut Deserializer<R, F>,
 }
 
 macro_rules! deserialize_integer_key {
@@ -2081,14 +3365,14 @@ macro_rules! deserialize_integer_key {
                     self.de.str_buf.clear();
                     string = try!(self.de.read.parse_double_str(&mut self.de.str_buf))
                 }
-                Some(b'\'') => {
+                Some(b'\'') if self.de.single_quotes_allowed() => {
                     self.de.eat_char();
                     self.de.str_buf.clear();
                     string = try!(self.de.read.parse_single_str(&mut self.de.str_buf))
                 }
                 Some(_) => {
                     self.de.str_buf.clear();
-                    string = try!(self.de.read.parse_none_str(&mut self.de.str_buf));
+                    string = try!(self.de.parse_none_str(true));
                 }
                 None => {
                     return Err(self.de.peek_error(ErrorCode::EofWhileParsingObject));
@@ -2104,9 +3388,10 @@ macro_rules! deserialize_integer_key {
     }
 }
 
-impl<'de, 'a, R> de::Deserializer<'de> for MapKey<'a, R>
+impl<'de, 'a, R, F> de::Deserializer<'de> for MapKey<'a, R, F>
 where
     R: Read<'de>,
+    F: Dialect,
 {
     type Error = Error;
 
@@ -2125,7 +3410,7 @@ where
                     Reference::Copied(s) => visitor.visit_str(s),
                 }
             },
-            b'\'' => {
+            b'\'' if self.de.single_quotes_allowed() => {
                 self.de.eat_char();
                 self.de.str_buf.clear();
                 match try!(self.de.read.parse_single_str(&mut self.de.str_buf)) {
@@ -2134,6 +3419,10 @@ where
                 }
             },
             _ => {
+                if !self.de.unquoted_keys_allowed() {
+                    return Err(self.de.peek_error(ErrorCode::ExpectedSomeValue));
+                }
+
                 self.de.str_buf.clear();
                 match try!(self.de.read.parse_member_name(&mut self.de.str_buf)) {
                     Reference::Borrowed(s) => visitor.visit_borrowed_str(s),
@@ -2229,17 +3518,61 @@ where
 ///     }
 /// }
 /// ```
-pub struct StreamDeserializer<'de, R, T> {
-    de: Deserializer<R>,
+pub struct StreamDeserializer<'de, R, T, F> {
+    de: Deserializer<R, F>,
     offset: usize,
+    line_delimited: bool,
+    /// Whether a value that starts on one line and finishes on a later one
+    /// is rejected as a framing error, for strict NDJSON ingestion. Setting
+    /// this also implies `line_delimited`.
+    strict_lines: bool,
+    recover: bool,
+    /// `Some(closing delimiter)` when iterating the elements of an array
+    /// reached via [`Deserializer::into_iter_at`]; `None` for the default
+    /// whitespace-delimited multi-value mode.
+    closer: Option<u8>,
+    /// Whether a `,` must precede the next element in `closer` mode.
+    expect_value: bool,
+    /// Set once the matching `closer` has been consumed, so `next()` keeps
+    /// returning `None` regardless of what follows in the document.
+    done: bool,
     output: PhantomData<T>,
     lifetime: PhantomData<&'de ()>,
 }
 
-impl<'de, R, T> StreamDeserializer<'de, R, T>
+/// The outcome of [`StreamDeserializer::next_partial`]: either a fully
+/// parsed value, or a signal that the buffer ran out before the next value
+/// could be finished.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Partial<T> {
+    /// A complete value was parsed.
+    Value(T),
+    /// The buffer ended before the next value could be fully read.
+    /// `byte_offset()` points at the start of this value; append more
+    /// bytes and call `next_partial()` again to resume.
+    Pending,
+}
+
+impl<T> Partial<T> {
+    /// Returns `true` if this is a [`Partial::Value`].
+    pub fn is_value(&self) -> bool {
+        match *self {
+            Partial::Value(_) => true,
+            Partial::Pending => false,
+        }
+    }
+
+    /// Returns `true` if this is [`Partial::Pending`].
+    pub fn is_pending(&self) -> bool {
+        !self.is_value()
+    }
+}
+
+impl<'de, R, T, F> StreamDeserializer<'de, R, T, F>
 where
     R: read::Read<'de>,
     T: de::Deserialize<'de>,
+    F: Dialect,
 {
     /// Create a JSON stream deserializer from one of the possible serde_json
     /// input sources.
@@ -2254,11 +3587,173 @@ where
         StreamDeserializer {
             de: Deserializer::new(read),
             offset: offset,
+            line_delimited: false,
+            strict_lines: false,
+            recover: false,
+            closer: None,
+            expect_value: false,
+            done: false,
+            output: PhantomData,
+            lifetime: PhantomData,
+        }
+    }
+
+    /// Create a JSON stream deserializer that requires exactly one value per
+    /// line, as in newline-delimited JSON (NDJSON) logs.
+    ///
+    /// Unlike the default stream mode, two values separated only by spaces or
+    /// tabs on the same line are rejected with a syntax error instead of being
+    /// silently treated as consecutive records.
+    pub fn new_line_delimited(read: R) -> Self {
+        let offset = read.byte_offset();
+        StreamDeserializer {
+            de: Deserializer::new(read),
+            offset: offset,
+            line_delimited: true,
+            strict_lines: false,
+            recover: false,
+            closer: None,
+            expect_value: false,
+            done: false,
             output: PhantomData,
             lifetime: PhantomData,
         }
     }
 
+    /// Create a strict JSON Lines (NDJSON) stream deserializer: like
+    /// [`new_line_delimited`](Self::new_line_delimited), but additionally
+    /// rejects any record whose parsed span crosses a newline (e.g. a
+    /// pretty-printed, multi-line object) as a framing error, instead of
+    /// silently accepting it.
+    ///
+    /// Use this over `new_line_delimited` when a record spanning multiple
+    /// lines by mistake should be caught rather than merged into the
+    /// following line's framing.
+    pub fn new_strict_line_delimited(read: R) -> Self {
+        let mut stream = Self::new_line_delimited(read);
+        stream.strict_lines = true;
+        stream
+    }
+
+    /// Enables or disables best-effort resynchronization after a parse
+    /// error.
+    ///
+    /// Disabled (the default), a malformed value poisons the rest of the
+    /// stream: every later `next()` call keeps failing at the same spot,
+    /// since nothing advances the underlying `Read` past the broken value.
+    /// Enabled, `next()` still yields the `Err` for the value that failed,
+    /// but first skips ahead to the next plausible value boundary -- a
+    /// `[`, `{`, or `"` following whitespace, or EOF -- so later calls can
+    /// resume yielding `Ok` values instead of repeating the same error
+    /// forever. This never kicks in for an EOF error, since there's no
+    /// later boundary to skip to; join more data at `byte_offset()` instead.
+    ///
+    /// Useful for ingesting concatenated/JSON-lines logs where an
+    /// occasional corrupt record shouldn't abort the whole stream.
+    pub fn set_recover(&mut self, recover: bool) {
+        self.recover = recover;
+    }
+
+    /// Enables or disables strict single-line-per-record framing, as in
+    /// [`new_strict_line_delimited`](Self::new_strict_line_delimited).
+    /// Enabling it also enables `line_delimited` framing, since a record
+    /// that may not span multiple lines only makes sense alongside a
+    /// required newline between records.
+    pub fn set_strict_lines(&mut self, strict: bool) {
+        self.strict_lines = strict;
+        if strict {
+            self.line_delimited = true;
+        }
+    }
+
+    /// Best-effort resynchronization after a parse error: advances the
+    /// underlying `Read` past the broken value, stopping at the next
+    /// whitespace-delimited token boundary (`[`, `{`, a quote, or EOF) so
+    /// `next()` can resume from a plausible value start.
+    fn resynchronize(&mut self) {
+        let mut prev_was_whitespace = false;
+        loop {
+            match self.de.read.peek() {
+                Ok(Some(b)) => {
+                    let is_value_start = match b {
+                        b'[' | b'{' | b'"' => true,
+                        _ => false,
+                    };
+                    if prev_was_whitespace && is_value_start {
+                        break;
+                    }
+                    prev_was_whitespace = match b {
+                        b' ' | b'\n' | b'\t' | b'\r' => true,
+                        _ => false,
+                    };
+                    self.de.eat_char();
+                }
+                Ok(None) | Err(_) => break,
+            }
+        }
+        self.offset = self.de.read.byte_offset();
+    }
+
+    /// `next()` body for a [`Deserializer::into_iter_at`] stream: identical
+    /// element-then-comma-or-close bookkeeping to `SeqAccess`, except it
+    /// treats `closer` as end of the whole iterator instead of the end of
+    /// one nested value.
+    fn next_in_array(&mut self, closer: u8) -> Option<Result<T>> {
+        match self.de.parse_whitespace() {
+            Ok(Some(b)) if b == closer => {
+                self.de.eat_char();
+                self.offset = self.de.read.byte_offset();
+                self.done = true;
+                if self.expect_value && !self.de.trailing_commas_allowed() {
+                    return Some(Err(self.de.peek_error(ErrorCode::ExtraComma)));
+                }
+                None
+            }
+            Ok(Some(b',')) => {
+                self.done = true;
+                Some(Err(self.de.peek_error(ErrorCode::ExtraComma)))
+            }
+            Ok(Some(_)) => {
+                self.offset = self.de.read.byte_offset();
+                self.expect_value = false;
+
+                let result = de::Deserialize::deserialize(&mut self.de).and_then(|value| {
+                    let mut had_newline = false;
+                    match try!(self.de.parse_whitespace_get_newline(&mut had_newline)) {
+                        Some(b',') => {
+                            self.de.eat_char();
+                            self.expect_value = true;
+                            Ok(value)
+                        }
+                        Some(b) if b == closer => Ok(value),
+                        Some(_) if had_newline || closer == b')' => Ok(value),
+                        Some(_) => Err(self.de.peek_error(ErrorCode::ExpectedListCommaOrEnd)),
+                        None => Err(self.de.peek_error(ErrorCode::EofWhileParsingList)),
+                    }
+                });
+
+                Some(match result {
+                    Ok(value) => {
+                        self.offset = self.de.read.byte_offset();
+                        Ok(value)
+                    }
+                    Err(e) => {
+                        self.done = true;
+                        Err(e)
+                    }
+                })
+            }
+            Ok(None) => {
+                self.done = true;
+                Some(Err(self.de.peek_error(ErrorCode::EofWhileParsingList)))
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+
     /// Returns the number of bytes so far deserialized into a successful `T`.
     ///
     /// If a stream deserializer returns an EOF error, new data can be joined to
@@ -2294,6 +3789,137 @@ where
         self.offset
     }
 
+    /// Like [`Iterator::next`], but deserializes into a caller-supplied
+    /// `place` via `Deserialize::deserialize_in_place` instead of
+    /// constructing and returning a fresh `T`. Reusing the same `place`
+    /// across iterations lets its `String`/`Vec` buffers be reused rather
+    /// than reallocated for every record, turning a streaming loop over a
+    /// large homogeneous array into amortized allocation.
+    ///
+    /// Returns `None` once the stream is exhausted, exactly like `next()`.
+    /// Not available in the [`Deserializer::into_iter_at`] array mode; use
+    /// `next()` there.
+    ///
+    /// ```rust
+    /// use serde_json::Deserializer;
+    ///
+    /// let data = b"1 2 3";
+    /// let mut stream = Deserializer::from_slice(data).into_iter::<i32>();
+    ///
+    /// let mut place = 0;
+    /// let mut sum = 0;
+    /// while let Some(result) = stream.next_in_place(&mut place) {
+    ///     result.unwrap();
+    ///     sum += place;
+    /// }
+    /// assert_eq!(6, sum);
+    /// ```
+    pub fn next_in_place(&mut self, place: &mut T) -> Option<Result<()>> {
+        if self.done {
+            return None;
+        }
+
+        if self.closer.is_some() {
+            return Some(Err(de::Error::custom(
+                "next_in_place is not supported while iterating an into_iter_at array",
+            )));
+        }
+
+        match self.de.parse_whitespace() {
+            Ok(None) => {
+                self.offset = self.de.read.byte_offset();
+                None
+            }
+            Ok(Some(b)) => {
+                let self_delineated_value = match b {
+                    b'[' | b'"' | b'{' => true,
+                    _ => false,
+                };
+                self.offset = self.de.read.byte_offset();
+                let start_line = self.de.read.peek_position().line;
+                let result = de::Deserialize::deserialize_in_place(&mut self.de, place);
+
+                Some(match result {
+                    Ok(()) => {
+                        self.offset = self.de.read.byte_offset();
+                        let end_check = if self_delineated_value {
+                            Ok(())
+                        } else {
+                            self.peek_end_of_value()
+                        };
+                        let result = end_check.and_then(|_| {
+                            if self.strict_lines && self.de.read.position().line != start_line {
+                                return Err(self.de.fix_position(de::Error::custom(
+                                    "record in a newline-delimited stream must not span multiple lines",
+                                )));
+                            }
+                            if self.line_delimited {
+                                try!(self.expect_record_separator());
+                            }
+                            Ok(())
+                        });
+                        if let Err(ref e) = result {
+                            if self.recover && !e.is_eof() {
+                                self.resynchronize();
+                            }
+                        }
+                        result
+                    }
+                    Err(e) => {
+                        if self.recover && !e.is_eof() {
+                            self.resynchronize();
+                        }
+                        Err(e)
+                    }
+                })
+            }
+            Err(e) => Some(Err(e))
+        }
+    }
+
+    /// Attempts to parse the next value the same way [`Iterator::next`]
+    /// does, but treats a truncated input as a distinct outcome from a
+    /// genuine parse error: if the buffer ends before the next value is
+    /// fully readable, returns `Ok(Partial::Pending)` instead of an EOF
+    /// [`Error`].
+    ///
+    /// This is the building block for reading JSON off a socket or pipe,
+    /// where values can arrive split across arbitrary chunk boundaries:
+    /// on `Pending`, `byte_offset()` is guaranteed to point at the start of
+    /// the not-yet-complete value (never mid-token), so appending more
+    /// bytes to the buffer and retrying from there is always correct.
+    ///
+    /// ```rust
+    /// use serde_json::{Deserializer, Partial};
+    ///
+    /// let buf = String::from(r#"{"id": 1} {"i"#);
+    /// let mut stream = Deserializer::from_str(&buf).into_iter::<serde_json::Value>();
+    ///
+    /// assert!(stream.next_partial().unwrap().is_value());
+    /// assert_eq!(Partial::Pending, stream.next_partial().unwrap());
+    /// ```
+    ///
+    /// *Note:* a bare number, `true`, `false` or `null` that happens to sit
+    /// exactly at the end of the currently available buffer is
+    /// indistinguishable from the same value immediately followed by the
+    /// end of the whole document, and so is reported as complete rather
+    /// than `Pending`. Values that need to survive arbitrary chunking
+    /// should be delimited (wrapped in an array/object, or read in
+    /// newline-delimited mode) rather than streamed bare.
+    pub fn next_partial(&mut self) -> Result<Partial<T>> {
+        match self.next() {
+            None => Ok(Partial::Pending),
+            Some(Ok(value)) => Ok(Partial::Value(value)),
+            Some(Err(e)) => {
+                if e.is_eof() {
+                    Ok(Partial::Pending)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
     fn peek_end_of_value(&mut self) -> Result<()> {
         match try!(self.de.peek()) {
             Some(b' ') | Some(b'\n') | Some(b'\t') | Some(b'\r') |
@@ -2305,16 +3931,45 @@ where
             },
         }
     }
+
+    /// In line-delimited mode, checks that nothing but a newline or EOF
+    /// follows the value just parsed, consuming any spaces or tabs in
+    /// between.
+    fn expect_record_separator(&mut self) -> Result<()> {
+        loop {
+            match try!(self.de.peek()) {
+                Some(b' ') | Some(b'\t') => {
+                    self.de.eat_char();
+                }
+                Some(b'\n') | Some(b'\r') | None => {
+                    return Ok(());
+                }
+                Some(_) => {
+                    let pos = self.de.read.peek_position();
+                    return Err(Error::syntax(ErrorCode::TrailingCharacters, pos.line, pos.column));
+                }
+            }
+        }
+    }
 }
 
-impl<'de, R, T> Iterator for StreamDeserializer<'de, R, T>
+impl<'de, R, T, F> Iterator for StreamDeserializer<'de, R, T, F>
 where
     R: Read<'de>,
     T: de::Deserialize<'de>,
+    F: Dialect,
 {
     type Item = Result<T>;
 
     fn next(&mut self) -> Option<Result<T>> {
+        if self.done {
+            return None;
+        }
+
+        if let Some(closer) = self.closer {
+            return self.next_in_array(closer);
+        }
+
         // skip whitespaces, if any
         // this helps with trailing whitespaces, since whitespaces between
         // values are handled for us.
@@ -2332,18 +3987,41 @@ where
                     _ => false,
                 };
                 self.offset = self.de.read.byte_offset();
+                let start_line = self.de.read.peek_position().line;
                 let result = de::Deserialize::deserialize(&mut self.de);
 
                 Some(match result {
                     Ok(value) => {
                         self.offset = self.de.read.byte_offset();
-                        if self_delineated_value {
-                            Ok(value)
+                        let end_check = if self_delineated_value {
+                            Ok(())
                         } else {
-                            self.peek_end_of_value().map(|_| value)
+                            self.peek_end_of_value()
+                        };
+                        let result = end_check.and_then(|_| {
+                            if self.strict_lines && self.de.read.position().line != start_line {
+                                return Err(self.de.fix_position(de::Error::custom(
+                                    "record in a newline-delimited stream must not span multiple lines",
+                                )));
+                            }
+                            if self.line_delimited {
+                                try!(self.expect_record_separator());
+                            }
+                            Ok(value)
+                        });
+                        if let Err(ref e) = result {
+                            if self.recover && !e.is_eof() {
+                                self.resynchronize();
+                            }
+                        }
+                        result
+                    }
+                    Err(e) => {
+                        if self.recover && !e.is_eof() {
+                            self.resynchronize();
                         }
+                        Err(e)
                     }
-                    Err(e) => Err(e)
                 })
             }
             Err(e) => Some(Err(e))
@@ -2351,14 +4029,23 @@ where
     }
 }
 
+impl<'de, R, T, F> iter::FusedIterator for StreamDeserializer<'de, R, T, F>
+where
+    R: Read<'de>,
+    T: de::Deserialize<'de>,
+    F: Dialect,
+{
+}
+
 //////////////////////////////////////////////////////////////////////////////
 
-fn from_trait<'de, R, T>(read: R) -> Result<T>
+fn from_trait<'de, R, T, F>(read: R) -> Result<T>
 where
     R: Read<'de>,
     T: de::Deserialize<'de>,
+    F: Dialect,
 {
-    let mut de = Deserializer::new(read);
+    let mut de: Deserializer<R, F> = Deserializer::new(read);
     let value = try!(de::Deserialize::deserialize(&mut de));
 
     // Make sure the whole stream has been consumed.
@@ -2366,6 +4053,20 @@ where
     Ok(value)
 }
 
+fn from_trait_seed<'de, R, S, F>(read: R, seed: S) -> Result<S::Value>
+where
+    R: Read<'de>,
+    S: de::DeserializeSeed<'de>,
+    F: Dialect,
+{
+    let mut de: Deserializer<R, F> = Deserializer::new(read);
+    let value = try!(seed.deserialize(&mut de));
+
+    // Make sure the whole stream has been consumed.
+    try!(de.end());
+    Ok(value)
+}
+
 /// Deserialize an instance of type `T` from an IO stream of JSON.
 ///
 /// # Errors
@@ -2413,12 +4114,30 @@ where
 ///     println!("{:#?}", u);
 /// }
 /// ```
+#[cfg(feature = "std")]
 pub fn from_reader<R, T>(rdr: R) -> Result<T>
 where
     R: io::Read,
     T: de::DeserializeOwned,
 {
-    from_trait(read::IoRead::new(rdr))
+    from_trait::<_, _, StrictJson>(read::IoRead::new(rdr))
+}
+
+/// Deserialize an instance of `S::Value` from an IO stream of JSON, driven
+/// by a caller-supplied [`DeserializeSeed`](de::DeserializeSeed) instead of
+/// a static [`Deserialize`](de::Deserialize) impl.
+///
+/// This is the seed-carrying counterpart of [`from_reader`]: use it when
+/// `T::deserialize` alone can't express what's needed, for example
+/// resolving fields against a schema passed in at call time, or populating
+/// a pre-allocated collection.
+#[cfg(feature = "std")]
+pub fn from_reader_seed<R, S>(rdr: R, seed: S) -> Result<S::Value>
+where
+    R: io::Read,
+    S: for<'de> de::DeserializeSeed<'de>,
+{
+    from_trait_seed::<_, _, StrictJson>(read::IoRead::new(rdr), seed)
 }
 
 /// Deserialize an instance of type `T` from bytes of JSON text.
@@ -2461,7 +4180,20 @@ pub fn from_slice<'a, T>(v: &'a [u8]) -> Result<T>
 where
     T: de::Deserialize<'a>,
 {
-    from_trait(read::SliceRead::new(v))
+    from_trait::<_, _, StrictJson>(read::SliceRead::new(v))
+}
+
+/// Deserialize an instance of `S::Value` from bytes of JSON text, driven by
+/// a caller-supplied [`DeserializeSeed`](de::DeserializeSeed) instead of a
+/// static [`Deserialize`](de::Deserialize) impl.
+///
+/// This is the seed-carrying counterpart of [`from_slice`]; see
+/// [`from_reader_seed`] for when this is useful.
+pub fn from_slice_seed<'a, S>(v: &'a [u8], seed: S) -> Result<S::Value>
+where
+    S: de::DeserializeSeed<'a>,
+{
+    from_trait_seed::<_, _, StrictJson>(read::SliceRead::new(v), seed)
 }
 
 /// Deserialize an instance of type `T` from a string of JSON text.
@@ -2504,5 +4236,60 @@ pub fn from_str<'a, T>(s: &'a str) -> Result<T>
 where
     T: de::Deserialize<'a>,
 {
-    from_trait(read::StrRead::new(s))
+    from_trait::<_, _, StrictJson>(read::StrRead::new(s))
+}
+
+/// Deserialize an instance of `S::Value` from a string of JSON text, driven
+/// by a caller-supplied [`DeserializeSeed`](de::DeserializeSeed) instead of
+/// a static [`Deserialize`](de::Deserialize) impl.
+///
+/// This is the seed-carrying counterpart of [`from_str`]; see
+/// [`from_reader_seed`] for when this is useful.
+pub fn from_str_seed<'a, S>(s: &'a str, seed: S) -> Result<S::Value>
+where
+    S: de::DeserializeSeed<'a>,
+{
+    from_trait_seed::<_, _, StrictJson>(read::StrRead::new(s), seed)
+}
+
+/// Deserialize one value of type `T` per line from a string of
+/// newline-delimited JSON (NDJSON/JSON Lines) text, without buffering the
+/// whole input.
+///
+/// The returned iterator stops cleanly at EOF and implements
+/// `core::iter::FusedIterator`, so it's safe to keep calling `next()` after
+/// the stream is exhausted.
+///
+/// ```rust
+/// let log = "{\"level\": \"info\"}\n{\"level\": \"warn\"}\n";
+///
+/// for entry in serde_json::iter_str::<serde_json::Value>(log) {
+///     println!("{}", entry.unwrap());
+/// }
+/// ```
+pub fn iter_str<'a, T>(s: &'a str) -> StreamDeserializer<'a, read::StrRead<'a>, T, StrictJson>
+where
+    T: de::Deserialize<'a>,
+{
+    StreamDeserializer::new_line_delimited(read::StrRead::new(s))
+}
+
+/// Like [`iter_str`], but additionally rejects a record whose parsed span
+/// crosses a newline (e.g. a pretty-printed, multi-line object) as a
+/// framing error, instead of silently merging it into the following line.
+///
+/// ```rust
+/// let log = "{\"level\": \"info\"}\n{\n  \"level\": \"warn\"\n}\n";
+///
+/// let mut stream = serde_json::iter_str_strict::<serde_json::Value>(log);
+/// assert!(stream.next().unwrap().is_ok());
+/// assert!(stream.next().unwrap().is_err());
+/// ```
+pub fn iter_str_strict<'a, T>(
+    s: &'a str,
+) -> StreamDeserializer<'a, read::StrRead<'a>, T, StrictJson>
+where
+    T: de::Deserialize<'a>,
+{
+    StreamDeserializer::new_strict_line_delimited(read::StrRead::new(s))
 }